@@ -8,7 +8,9 @@
 //! see the [`windows`](trait.WindowedIterator.html#method.windows) method on
 //! [`WindowedIterator`](trait.WindowedIterator.html).
 
+use std::cell::{Cell, UnsafeCell};
 use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
 
 /// A sliding window iterator adapter.
 ///
@@ -18,8 +20,8 @@ use std::collections::VecDeque;
 /// for more information.
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct Windows<I: Iterator<Item = T>, T: Clone> {
-  /// The adapted iterator.
-  iter: I,
+  /// The adapted iterator, fused so that exhaustion is permanent.
+  iter: std::iter::Fuse<I>,
   /// The window.
   window: VecDeque<T>,
   /// The number of elements in the window.
@@ -31,7 +33,7 @@ impl<I: Iterator<Item = T>, T: Clone> Windows<I, T> {
   /// number of elements in the window is given with `window_size`.
   fn new(iter: I, window_size: usize) -> Self {
     Windows {
-      iter,
+      iter: iter.fuse(),
       window: VecDeque::with_capacity(window_size),
       window_size,
     }
@@ -62,6 +64,411 @@ impl<I: Iterator<Item = T>, T: Clone> Iterator for Windows<I, T> {
     // avoid lifetime issues.
     Some(self.window.clone())
   }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    if self.window_size == 0 {
+      return (0, Some(0));
+    }
+
+    // Once the window has been filled once it stays full between calls to
+    // `next`, so every remaining element of `iter` produces exactly one
+    // more window. Before that, a full `window_size` elements are needed
+    // just to produce the first one.
+    let windows_remaining = |inner_len: usize| {
+      if self.window.len() == self.window_size {
+        inner_len
+      } else {
+        let total = inner_len + self.window.len();
+        total.checked_sub(self.window_size).map_or(0, |n| n + 1)
+      }
+    };
+
+    let (inner_lower, inner_upper) = self.iter.size_hint();
+    (
+      windows_remaining(inner_lower),
+      inner_upper.map(windows_remaining),
+    )
+  }
+}
+
+impl<I: ExactSizeIterator<Item = T>, T: Clone> ExactSizeIterator for Windows<I, T> {}
+
+impl<I: Iterator<Item = T>, T: Clone> std::iter::FusedIterator for Windows<I, T> {}
+
+/// Reusable backing storage for the [`windows_ref`](trait.WindowedIterator.html#method.windows_ref)
+/// adapter.
+///
+/// A `Storage<T>` is constructed once with a fixed `capacity` and reused for
+/// every window produced by [`WindowsRef`](struct.WindowsRef.html), which
+/// avoids the per-iteration cloning that [`Windows`](struct.Windows.html)
+/// requires. The buffer is a plain `Vec<T>` rather than a `VecDeque<T>` so
+/// that it is always contiguous: shifting the window drops the oldest
+/// element with `Vec::remove(0)` instead of rotating, which means a
+/// [`Window`](struct.Window.html) can safely hand out a `&[T]` without ever
+/// having to reorder memory that an outstanding [`iter`](struct.Window.html#method.iter)
+/// or [`iter_mut`](struct.Window.html#method.iter_mut) borrow might be
+/// observing.
+pub struct Storage<T> {
+  buffer: UnsafeCell<Vec<T>>,
+  capacity: usize,
+  /// Set while a [`Window`](struct.Window.html) borrowing this storage is
+  /// alive. Since that borrow is not visible to the borrow checker (it goes
+  /// through the shared `&'a Storage<T>` held by [`WindowsRef`](struct.WindowsRef.html),
+  /// not through the `&mut self` of its `next`),
+  /// this flag is what actually prevents two windows from aliasing the same
+  /// buffer at once.
+  borrowed: Cell<bool>,
+}
+
+impl<T> Storage<T> {
+  /// Create a new, empty `Storage` with room for `capacity` elements.
+  pub fn new(capacity: usize) -> Self {
+    Storage {
+      buffer: UnsafeCell::new(Vec::with_capacity(capacity)),
+      capacity,
+      borrowed: Cell::new(false),
+    }
+  }
+}
+
+/// A window borrowed from a [`Storage`](struct.Storage.html) buffer.
+///
+/// Unlike the `VecDeque` yielded by [`Windows`](struct.Windows.html), a
+/// `Window` does not own its elements: it dereferences to a `&[T]` slice
+/// borrowed from the underlying [`Storage`](struct.Storage.html), so
+/// producing one never allocates or clones. Only one `Window` borrowing a
+/// given `Storage` may be alive at a time; dropping it releases the storage
+/// so that [`WindowsRef::next`](struct.WindowsRef.html) can hand out the
+/// next one.
+pub struct Window<'a, T> {
+  storage: &'a Storage<T>,
+}
+
+impl<'a, T> Window<'a, T> {
+  /// Return an iterator over references to the elements of the window.
+  pub fn iter(&self) -> std::slice::Iter<'_, T> {
+    unsafe { &*self.storage.buffer.get() }.iter()
+  }
+
+  /// Return an iterator over mutable references to the elements of the
+  /// window.
+  pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+    unsafe { &mut *self.storage.buffer.get() }.iter_mut()
+  }
+}
+
+impl<'a, T> Deref for Window<'a, T> {
+  type Target = [T];
+
+  fn deref(&self) -> &[T] {
+    unsafe { &*self.storage.buffer.get() }
+  }
+}
+
+impl<'a, T> DerefMut for Window<'a, T> {
+  fn deref_mut(&mut self) -> &mut [T] {
+    unsafe { &mut *self.storage.buffer.get() }
+  }
+}
+
+impl<'a, T> Drop for Window<'a, T> {
+  fn drop(&mut self) {
+    self.storage.borrowed.set(false);
+  }
+}
+
+/// A zero-copy sliding window iterator adapter.
+///
+/// This `struct` is created by the
+/// [`windows_ref`](trait.WindowedIterator.html#method.windows_ref) method on
+/// [`WindowedIterator`](trait.WindowedIterator.html). See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WindowsRef<'a, I: Iterator<Item = T>, T> {
+  /// The adapted iterator.
+  iter: I,
+  /// The reusable backing storage for the window.
+  storage: &'a Storage<T>,
+  /// The number of elements in the window.
+  window_size: usize,
+}
+
+impl<'a, I: Iterator<Item = T>, T> WindowsRef<'a, I, T> {
+  /// Create a new instance of [`WindowsRef`](struct.WindowsRef.html) where
+  /// the number of elements in the window is given with `window_size`.
+  fn new(iter: I, storage: &'a mut Storage<T>, window_size: usize) -> Self {
+    assert!(
+      storage.capacity >= window_size,
+      "`Storage` capacity must be at least `window_size`"
+    );
+    // `storage` may be left over from a previous `WindowsRef`, e.g. one that
+    // was dropped mid-stream or fully drained; reset it so this iterator
+    // starts from a clean slate rather than inheriting stale elements.
+    storage.buffer.get_mut().clear();
+    *storage.borrowed.get_mut() = false;
+    WindowsRef {
+      iter,
+      storage: &*storage,
+      window_size,
+    }
+  }
+}
+
+impl<'a, I: Iterator<Item = T>, T> Iterator for WindowsRef<'a, I, T> {
+  type Item = Window<'a, T>;
+
+  fn next(&mut self) -> Option<Window<'a, T>> {
+    if self.window_size == 0 {
+      return None;
+    }
+
+    assert!(
+      !self.storage.borrowed.get(),
+      "a previous `Window` must be dropped before requesting another"
+    );
+
+    let buffer = unsafe { &mut *self.storage.buffer.get() };
+    if buffer.len() == self.window_size {
+      buffer.remove(0);
+    }
+
+    while buffer.len() < self.window_size {
+      match self.iter.next() {
+        Some(elem) => buffer.push(elem),
+        None => return None,
+      }
+    }
+
+    self.storage.borrowed.set(true);
+    Some(Window {
+      storage: self.storage,
+    })
+  }
+}
+
+/// Build a `[T; N]` array by cloning the first `N` elements of `deque`.
+///
+/// Used by [`MapWindows`](struct.MapWindows.html) to materialise the
+/// fixed-size window that its `VecDeque` buffer represents.
+fn array_from_deque<T: Clone, const N: usize>(deque: &VecDeque<T>) -> [T; N] {
+  std::array::from_fn(|i| deque[i].clone())
+}
+
+/// A const-generic sliding window iterator adapter.
+///
+/// This `struct` is created by the
+/// [`array_windows`](trait.WindowedIterator.html#method.array_windows)
+/// method on [`WindowedIterator`](trait.WindowedIterator.html). See its
+/// documentation for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayWindows<I: Iterator<Item = T>, T: Clone, const N: usize> {
+  /// The adapted iterator.
+  iter: I,
+  /// The window, kept as a fixed-size ring buffer so that producing one
+  /// never allocates on the heap. `window[head]` is the oldest element and
+  /// the rest follow in order, wrapping around the end of the array.
+  window: [Option<T>; N],
+  /// The index of the oldest element in `window`.
+  head: usize,
+  /// The number of elements currently filled in `window`, which ramps up
+  /// to `N` and then stays there.
+  len: usize,
+}
+
+impl<I: Iterator<Item = T>, T: Clone, const N: usize> ArrayWindows<I, T, N> {
+  /// Create a new instance of [`ArrayWindows`](struct.ArrayWindows.html)
+  /// where the number of elements in the window is given by `N`.
+  fn new(iter: I) -> Self {
+    ArrayWindows {
+      iter,
+      window: std::array::from_fn(|_| None),
+      head: 0,
+      len: 0,
+    }
+  }
+}
+
+impl<I: Iterator<Item = T>, T: Clone, const N: usize> Iterator for ArrayWindows<I, T, N> {
+  type Item = [T; N];
+
+  fn next(&mut self) -> Option<[T; N]> {
+    if N == 0 {
+      return None;
+    }
+
+    if self.len == N {
+      // The window is already full: overwrite the oldest slot with the
+      // next element and advance `head` past it.
+      self.window[self.head] = Some(self.iter.next()?);
+      self.head = (self.head + 1) % N;
+    } else {
+      while self.len < N {
+        self.window[self.len] = Some(self.iter.next()?);
+        self.len += 1;
+      }
+    }
+
+    Some(std::array::from_fn(|i| {
+      self.window[(self.head + i) % N]
+        .clone()
+        .expect("window slots up to `len` should be filled")
+    }))
+  }
+}
+
+/// A lazy, mapping sliding window iterator adapter.
+///
+/// This `struct` is created by the
+/// [`map_window`](trait.WindowedIterator.html#method.map_window) method on
+/// [`WindowedIterator`](trait.WindowedIterator.html). See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapWindows<I: Iterator<Item = T>, T: Clone, F, R, const N: usize>
+where
+  F: FnMut(&[T; N]) -> R,
+{
+  /// The adapted iterator, fused so that exhaustion is permanent.
+  iter: std::iter::Fuse<I>,
+  /// The window.
+  window: VecDeque<T>,
+  /// The closure applied to each window.
+  f: F,
+}
+
+impl<I: Iterator<Item = T>, T: Clone, F, R, const N: usize> MapWindows<I, T, F, R, N>
+where
+  F: FnMut(&[T; N]) -> R,
+{
+  /// Create a new instance of [`MapWindows`](struct.MapWindows.html) where
+  /// the number of elements in the window is given by `N`.
+  fn new(iter: I, f: F) -> Self {
+    MapWindows {
+      iter: iter.fuse(),
+      window: VecDeque::with_capacity(N),
+      f,
+    }
+  }
+}
+
+impl<I: Iterator<Item = T>, T: Clone, F, R, const N: usize> Iterator for MapWindows<I, T, F, R, N>
+where
+  F: FnMut(&[T; N]) -> R,
+{
+  type Item = R;
+
+  fn next(&mut self) -> Option<R> {
+    if N == 0 {
+      return None;
+    }
+
+    if self.window.len() == N {
+      self.window.pop_front();
+    }
+
+    while self.window.len() < N {
+      match self.iter.next() {
+        Some(elem) => self.window.push_back(elem),
+        None => return None,
+      }
+    }
+
+    let window = array_from_deque(&self.window);
+    Some((self.f)(&window))
+  }
+}
+
+impl<I: Iterator<Item = T>, T: Clone, F, R, const N: usize> std::iter::FusedIterator
+  for MapWindows<I, T, F, R, N>
+where
+  F: FnMut(&[T; N]) -> R,
+{
+}
+
+/// A wrap-around sliding window iterator adapter.
+///
+/// This `struct` is created by the
+/// [`circular_windows`](trait.WindowedIterator.html#method.circular_windows)
+/// method on [`WindowedIterator`](trait.WindowedIterator.html). See its
+/// documentation for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CircularWindows<I: Iterator<Item = T>, T: Clone> {
+  /// The adapted iterator.
+  iter: I,
+  /// The first `window_size - 1` elements seen, buffered so that they can
+  /// be replayed once `iter` is exhausted, wrapping the window back around
+  /// to the start.
+  head: VecDeque<T>,
+  /// The window.
+  window: VecDeque<T>,
+  /// The number of elements in the window.
+  window_size: usize,
+  /// Whether a full window has been assembled at least once, i.e. whether
+  /// the adapted iterator holds at least `window_size` elements in total.
+  primed: bool,
+  /// The number of wrap-around windows still to be produced by replaying
+  /// `head` once `iter` is exhausted.
+  replays_left: usize,
+}
+
+impl<I: Iterator<Item = T>, T: Clone> CircularWindows<I, T> {
+  /// Create a new instance of [`CircularWindows`](struct.CircularWindows.html)
+  /// where the number of elements in the window is given by `window_size`.
+  fn new(iter: I, window_size: usize) -> Self {
+    CircularWindows {
+      iter,
+      head: VecDeque::with_capacity(window_size.saturating_sub(1)),
+      window: VecDeque::with_capacity(window_size),
+      window_size,
+      primed: false,
+      replays_left: 0,
+    }
+  }
+
+  /// Pull the next element of the window from the adapted iterator, or,
+  /// once it is exhausted, from the buffered `head` elements.
+  fn pull(&mut self) -> Option<T> {
+    if let Some(elem) = self.iter.next() {
+      if self.head.len() < self.window_size - 1 {
+        self.head.push_back(elem.clone());
+      }
+      return Some(elem);
+    }
+
+    if self.primed && self.replays_left > 0 {
+      self.replays_left -= 1;
+      return self.head.pop_front();
+    }
+
+    None
+  }
+}
+
+impl<I: Iterator<Item = T>, T: Clone> Iterator for CircularWindows<I, T> {
+  type Item = VecDeque<T>;
+
+  fn next(&mut self) -> Option<VecDeque<T>> {
+    if self.window_size == 0 {
+      return None;
+    }
+
+    if self.window.len() == self.window_size {
+      self.window.pop_front();
+    }
+
+    while self.window.len() < self.window_size {
+      match self.pull() {
+        Some(elem) => self.window.push_back(elem),
+        None => return None,
+      }
+    }
+
+    if !self.primed {
+      self.primed = true;
+      self.replays_left = self.window_size - 1;
+    }
+
+    Some(self.window.clone())
+  }
 }
 
 /// An `Iterator` blanket implementation that provides
@@ -121,12 +528,168 @@ pub trait WindowedIterator<I: Iterator<Item = T>, T: Clone> {
   /// # }
   /// ```
   fn windows(self, window_size: usize) -> Windows<I, T>;
+
+  /// Return a "sliding window" iterator like [`windows`](#method.windows),
+  /// except that each [`Window`](struct.Window.html) borrows its elements
+  /// from a caller-supplied [`Storage`](struct.Storage.html) buffer instead
+  /// of cloning them.
+  ///
+  /// This is the adapter to reach for when `T` is expensive to clone, since
+  /// it never allocates or clones once `storage` has been filled. The
+  /// trade-off is that only one `Window` may be alive at a time; calling
+  /// `next` while a previous `Window` is still in scope panics.
+  ///
+  /// # Panics
+  ///
+  /// * If `storage`'s capacity is less than `window_size`.
+  /// * If `next` is called on the returned iterator while a `Window` it
+  ///   previously yielded has not yet been dropped.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # fn main() {
+  /// # use windowed_iterator::{Storage, WindowedIterator};
+  /// let words = vec!["These", "are", "a", "bunch", "of", "words"];
+  /// let mut storage = Storage::new(3);
+  /// let mut iter = words.windows_ref(&mut storage, 3);
+  ///
+  /// assert_eq!(&*iter.next().unwrap(), ["These", "are", "a"]);
+  /// assert_eq!(&*iter.next().unwrap(), ["are", "a", "bunch"]);
+  /// # }
+  /// ```
+  fn windows_ref<'a>(
+    self,
+    storage: &'a mut Storage<T>,
+    window_size: usize,
+  ) -> WindowsRef<'a, I, T>;
+
+  /// Return a "sliding window" iterator like [`windows`](#method.windows),
+  /// except that the window size `N` is fixed at compile time and each item
+  /// is a `[T; N]` array rather than a `VecDeque<T>`. This avoids the heap
+  /// allocation `VecDeque` requires and is more ergonomic to destructure,
+  /// e.g. `let [a, b, c] = window;`.
+  ///
+  /// # Behaviour to Note
+  ///
+  /// This adapter follows the same rules as [`windows`](#method.windows):
+  /// a window size of `N == 0`, or an iterator shorter than `N`, yields an
+  /// empty iterator.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # fn main() {
+  /// # use windowed_iterator::WindowedIterator;
+  /// let words = vec!["These", "are", "a", "bunch", "of", "words"];
+  /// let mut iter = words.array_windows::<3>();
+  ///
+  /// assert_eq!(iter.next().unwrap(), ["These", "are", "a"]);
+  /// assert_eq!(iter.next().unwrap(), ["are", "a", "bunch"]);
+  /// # }
+  /// ```
+  fn array_windows<const N: usize>(self) -> ArrayWindows<I, T, N>;
+
+  /// Return a lazy iterator that applies `f` to each contiguous window of
+  /// size `N`, yielding the closure's result instead of the window itself.
+  ///
+  /// This is cheaper than calling [`windows`](#method.windows) followed by
+  /// `.map(f)` when `R` only needs to retain a small summary of the window
+  /// (a sum, a diff, a parsed token, ...), since each window is handed to `f`
+  /// as a stack-allocated array rather than a freshly cloned `VecDeque`. The
+  /// underlying iterator is
+  /// fused, so once it is exhausted the returned iterator yields `None`
+  /// forever.
+  ///
+  /// This is named `map_window` rather than `map_windows` to avoid colliding
+  /// with the nightly-only, currently unstable `Iterator::map_windows`,
+  /// which would otherwise trigger an `unstable_name_collisions` warning for
+  /// callers on stable.
+  ///
+  /// # Behaviour to Note
+  ///
+  /// As with [`array_windows`](#method.array_windows), a window size of
+  /// `N == 0`, or an iterator shorter than `N`, yields an empty iterator.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # fn main() {
+  /// # use windowed_iterator::WindowedIterator;
+  /// let numbers = vec![1, 2, 3, 4, 5];
+  /// let mut sums = numbers.map_window(|window: &[i32; 2]| window.iter().sum::<i32>());
+  ///
+  /// assert_eq!(sums.next(), Some(3));
+  /// assert_eq!(sums.next(), Some(5));
+  /// assert_eq!(sums.next(), Some(7));
+  /// assert_eq!(sums.next(), Some(9));
+  /// assert_eq!(sums.next(), None);
+  /// # }
+  /// ```
+  fn map_window<R, F, const N: usize>(self, f: F) -> MapWindows<I, T, F, R, N>
+  where
+    F: FnMut(&[T; N]) -> R;
+
+  /// Return a "sliding window" iterator like [`windows`](#method.windows),
+  /// except that once the end of the collection is reached it wraps back
+  /// around to the start, so that every element gets to be the first
+  /// element of a window. For input `[a, b, c]` with a `window_size` of 3,
+  /// this yields `[a, b, c]`, `[b, c, a]`, then `[c, a, b]`.
+  ///
+  /// # Behaviour to Note
+  ///
+  /// * Exactly as many windows are yielded as there are elements in the
+  ///   collection.
+  /// * A window size of 0 will yield an empty iterator.
+  /// * A window size that is greater than the amount of elements in the
+  ///   iterator will yield an empty iterator.
+  /// * As with [`windows`](#method.windows), the elements are cloned so
+  ///   that they can be part of successive windows.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # fn main() {
+  /// # use windowed_iterator::WindowedIterator;
+  /// let letters = vec!['a', 'b', 'c'];
+  /// let mut iter = letters.circular_windows(3);
+  ///
+  /// assert_eq!(iter.next().unwrap(), ['a', 'b', 'c']);
+  /// assert_eq!(iter.next().unwrap(), ['b', 'c', 'a']);
+  /// assert_eq!(iter.next().unwrap(), ['c', 'a', 'b']);
+  /// assert!(iter.next().is_none());
+  /// # }
+  /// ```
+  fn circular_windows(self, window_size: usize) -> CircularWindows<I, T>;
 }
 
 impl<I: IntoIterator<Item = T>, T: Clone> WindowedIterator<I::IntoIter, T> for I {
   fn windows(self, window_size: usize) -> Windows<I::IntoIter, T> {
     Windows::new(self.into_iter(), window_size)
   }
+
+  fn windows_ref<'a>(
+    self,
+    storage: &'a mut Storage<T>,
+    window_size: usize,
+  ) -> WindowsRef<'a, I::IntoIter, T> {
+    WindowsRef::new(self.into_iter(), storage, window_size)
+  }
+
+  fn array_windows<const N: usize>(self) -> ArrayWindows<I::IntoIter, T, N> {
+    ArrayWindows::new(self.into_iter())
+  }
+
+  fn map_window<R, F, const N: usize>(self, f: F) -> MapWindows<I::IntoIter, T, F, R, N>
+  where
+    F: FnMut(&[T; N]) -> R,
+  {
+    MapWindows::new(self.into_iter(), f)
+  }
+
+  fn circular_windows(self, window_size: usize) -> CircularWindows<I::IntoIter, T> {
+    CircularWindows::new(self.into_iter(), window_size)
+  }
 }
 
 #[cfg(test)]
@@ -149,5 +712,143 @@ mod tests {
     fn test_empty_window(x: Vec<isize>) {
       assert!(x.iter().windows(0).next().is_none());
     }
+
+    // Mirrors `test_random_window_size`, but for the zero-copy adapter.
+    #[test]
+    fn test_random_window_size_ref(x: Vec<isize>, size: u16) {
+      let mut storage = Storage::new(size as usize);
+      for window in x.windows_ref(&mut storage, size as usize) {
+        assert_ne!(window.len(), 0);
+      }
+    }
+
+    // Mirrors `test_random_window_size`, but for `array_windows`. `N` is a
+    // const generic and so can't vary at runtime like `windows`' size does;
+    // this instead fuzzes the input over a fixed window size.
+    #[test]
+    fn test_random_array_window_size(x: Vec<isize>) {
+      for window in x.iter().cloned().array_windows::<3>() {
+        assert_eq!(window.len(), 3);
+      }
+    }
+
+    #[test]
+    fn test_empty_array_window(x: Vec<isize>) {
+      assert!(x.iter().cloned().array_windows::<0>().next().is_none());
+    }
+
+    // Mirrors `test_random_window_size`, but for `circular_windows`.
+    #[test]
+    fn test_random_circular_window_size(x: Vec<isize>, size: u16) {
+      let len = x.len();
+      let size = size as usize;
+      let windows: Vec<_> = x.circular_windows(size).collect();
+
+      if size == 0 || size > len {
+        assert!(windows.is_empty());
+      } else {
+        assert_eq!(windows.len(), len);
+        for window in &windows {
+          assert_eq!(window.len(), size);
+        }
+      }
+    }
+
+    #[test]
+    fn test_empty_circular_window(x: Vec<isize>) {
+      assert!(x.iter().cloned().circular_windows(0).next().is_none());
+    }
+
+    // `size_hint` (and therefore `ExactSizeIterator::len`) should always
+    // match the number of windows actually yielded.
+    #[test]
+    fn test_windows_size_hint_matches_yielded_count(x: Vec<isize>, size: u16) {
+      let size = size as usize;
+      let iter = x.iter().cloned().windows(size);
+      let len = iter.len();
+      let (lower, upper) = iter.size_hint();
+      let actual = iter.count();
+
+      assert_eq!(len, actual);
+      assert_eq!(lower, actual);
+      assert_eq!(upper, Some(actual));
+    }
+  }
+
+  #[test]
+  fn test_windows_size_hint_window_larger_than_input() {
+    let x = vec![1, 2, 3];
+    let iter = x.iter().cloned().windows(10);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.len(), 0);
+  }
+
+  #[test]
+  fn test_windows_size_hint_zero_window_size() {
+    let x = vec![1, 2, 3];
+    let iter = x.iter().cloned().windows(0);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.len(), 0);
+  }
+
+  #[test]
+  #[should_panic(expected = "a previous `Window` must be dropped before requesting another")]
+  fn test_windows_ref_panics_on_overlapping_borrow() {
+    let words = vec!["a", "b", "c", "d"];
+    let mut storage = Storage::new(2);
+    let mut iter = words.windows_ref(&mut storage, 2);
+
+    let first = iter.next().unwrap();
+    let _second = iter.next();
+    drop(first);
+  }
+
+  #[test]
+  fn test_windows_ref_reuse_after_drop_mid_stream() {
+    let mut storage = Storage::new(3);
+    {
+      let data = vec![1, 2, 3, 4, 5];
+      let mut iter = data.windows_ref(&mut storage, 3);
+      iter.next();
+      iter.next();
+      // `iter` (and the borrow of `storage` it held) is dropped here,
+      // mid-stream, before `storage` is reused below.
+    }
+
+    let data = vec![10, 20, 30];
+    let mut iter = data.windows_ref(&mut storage, 3);
+    assert_eq!(&*iter.next().unwrap(), [10, 20, 30]);
+  }
+
+  #[test]
+  fn test_windows_ref_reuse_after_drain_with_smaller_window() {
+    let mut storage = Storage::new(5);
+    {
+      let data = vec![1, 2, 3, 4, 5];
+      let mut iter = data.windows_ref(&mut storage, 5);
+      while iter.next().is_some() {}
+    }
+
+    let data = vec![10, 20];
+    let mut iter = data.windows_ref(&mut storage, 2);
+    let window = iter.next().unwrap();
+    assert_eq!(window.len(), 2);
+    assert_eq!(&*window, [10, 20]);
+  }
+
+  #[test]
+  fn test_map_window_fuses() {
+    let x = vec![1, 2, 3];
+    let mut iter = x
+      .iter()
+      .cloned()
+      .map_window(|window: &[i32; 2]| window.iter().sum::<i32>());
+
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), Some(5));
+    assert_eq!(iter.next(), None);
+    // Once exhausted, `map_window` must keep yielding `None`.
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
   }
 }